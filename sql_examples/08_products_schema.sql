@@ -67,6 +67,7 @@ CREATE INDEX idx_products_category ON products.items (category);
 CREATE INDEX idx_products_price ON products.items (price);
 CREATE INDEX idx_products_rating ON products.items (rating);
 CREATE INDEX idx_products_in_stock ON products.items (in_stock);
+CREATE INDEX idx_products_created_at ON products.items (created_at DESC);
 
 -- Create GIN indexes for array/JSONB
 CREATE INDEX idx_products_tags ON products.items USING gin (tags);
@@ -273,6 +273,16 @@ WHERE description ||| 'wireless bluetooth'
 ORDER BY pdb.score(id) DESC
 LIMIT 10;
 
+-- Test 17: Performance check - Newest sort on a match-all browse
+\echo 'Test 17: EXPLAIN - Newest sort uses created_at index'
+SET enable_seqscan = off;
+EXPLAIN
+SELECT id, name, created_at
+FROM test_products.items
+ORDER BY created_at DESC
+LIMIT 10;
+RESET enable_seqscan;
+
 --------------------------------------------------------------------------------
 -- TEARDOWN: Clean up test environment
 --------------------------------------------------------------------------------
@@ -171,6 +171,7 @@ BEGIN
     CREATE INDEX idx_test_products_price ON test_products.items (price);
     CREATE INDEX idx_test_products_rating ON test_products.items (rating);
     CREATE INDEX idx_test_products_in_stock ON test_products.items (in_stock);
+    CREATE INDEX idx_test_products_created_at ON test_products.items (created_at DESC);
     status := 'OK';
     details := 'B-tree indexes created';
     RETURN NEXT;